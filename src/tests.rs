@@ -8,8 +8,8 @@ use std::sync::{
 };
 
 use eframe::egui;
-use crate::primes::{              
-    PrimeType, compute_with_memo,
+use crate::primes::{
+    PrimeType, compute_stream,
 };
 use std::thread;
 
@@ -28,21 +28,30 @@ fn spawn_worker(
     ctx: egui::Context
 ) {
     thread::spawn(move || {
-        let v = compute_with_memo(kind, start, end);
-        for (i, s) in v.iter().enumerate() {
+        // Pull members lazily so a huge range neither balloons memory nor
+        // blocks cancellation before the first repaint.
+        let mut stream = compute_stream(kind, start, end);
+        loop {
             if stop.load(Ordering::SeqCst) {
                 *out.lock().unwrap() = "stopped".to_string();
                 break;
             }
-            {
-                let mut o = out.lock().unwrap();
-                if !o.is_empty() {
-                    o.push_str(", ");
+            match stream.next() {
+                Some(s) => {
+                    {
+                        let mut o = out.lock().unwrap();
+                        if !o.is_empty() {
+                            o.push_str(", ");
+                        }
+                        o.push_str(&s);
+                    }
+                    // Progress is measured by position in the range, not by how
+                    // many results have been emitted.
+                    *prog.lock().unwrap() = stream.progress();
+                    ctx.request_repaint();
                 }
-                o.push_str(s);
+                None => break,
             }
-            *prog.lock().unwrap() = (i + 1) as f32 / v.len() as f32;
-            ctx.request_repaint();
         }
         *prog.lock().unwrap() = 1.0;
         ctx.request_repaint();
@@ -112,11 +121,17 @@ fn range_swap_self_heals() {
 }
 
 #[test]
-fn mersenne_overflow_is_skipped() {
-    // exponent 70 would overflow u64 in 2^p−1; expect empty text
+fn mersenne_past_u64_is_computed() {
+    // exponents 70 and 71 overflow u64 in 2^p−1 but are composite Mersenne
+    // numbers, so the range is legitimately empty — no longer a silent skip.
     let (txt, pct) = run_once(PrimeType::Mersenne, 70, 72);
-    assert!(txt.is_empty());      // silently skipped, no panic
+    assert!(txt.is_empty());
     assert_eq!(pct, 1.0);
+
+    // exponent 89 is a genuine Mersenne prime well past u64; the arbitrary-
+    // precision path yields its true decimal value instead of nothing.
+    let (big, _) = run_once(PrimeType::Mersenne, 89, 90);
+    assert_eq!(big, "618970019642690137449562111");
 }
 
 #[test]
@@ -1,19 +1,78 @@
-//! Clipboard helper (only compiles with `--features clipboard`).
+//! Clipboard + structured export helpers.
+//!
+//! A computed result is carried as an [`ExportPayload`] and serialized through
+//! [`export`] into one of several [`ExportFormat`]s, so results can be piped
+//! into spreadsheets or other tools instead of being re-parsed from the UI
+//! string. Both clipboard copy and file save route through the same function.
 
 #[cfg(feature = "clipboard")]
 use arboard::Clipboard;
 
-use crate::primes::PrimeError;
+use crate::primes::{PrimeError, PrimeType};
+
+/// A completed calculation ready to be serialized.
+pub struct ExportPayload {
+    pub kind: PrimeType,
+    pub start: u64,
+    pub end: u64,
+    pub values: Vec<String>,
+}
+
+/// Output formats understood by [`export`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Flat, comma-joined list (the historical clipboard format).
+    PlainText,
+    /// One prime — or one pair, split into columns — per row.
+    Csv,
+    /// A single object carrying `{kind, start, end, values}`.
+    Json,
+}
+
+fn escape_json(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Serialize `result` into the requested `format`.
+pub fn export(result: &ExportPayload, format: ExportFormat) -> Result<String, PrimeError> {
+    let out = match format {
+        ExportFormat::PlainText => result.values.join(", "),
+        ExportFormat::Csv => {
+            // Pair families emit `(a,b)`; drop the parens so each tuple becomes
+            // a proper multi-column row, leaving scalar values untouched.
+            result
+                .values
+                .iter()
+                .map(|v| v.trim_start_matches('(').trim_end_matches(')').to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+        ExportFormat::Json => {
+            let values = result
+                .values
+                .iter()
+                .map(|v| format!("\"{}\"", escape_json(v)))
+                .collect::<Vec<_>>()
+                .join(",");
+            format!(
+                "{{\"kind\":\"{:?}\",\"start\":{},\"end\":{},\"values\":[{}]}}",
+                result.kind, result.start, result.end, values
+            )
+        }
+    };
+    Ok(out)
+}
 
 #[cfg(feature = "clipboard")]
-pub fn copy_to_clipboard(text: &str) -> Result<(), PrimeError> {
+pub fn copy_to_clipboard(result: &ExportPayload, format: ExportFormat) -> Result<(), PrimeError> {
+    let text = export(result, format)?;
     Clipboard::new()
         .map_err(|_| PrimeError::Fatal("clipboard init"))?
-        .set_text(text.to_owned())
+        .set_text(text)
         .map_err(|_| PrimeError::Fatal("clipboard set"))
 }
 
 #[cfg(not(feature = "clipboard"))]
-pub fn copy_to_clipboard(_: &str) -> Result<(), PrimeError> {
+pub fn copy_to_clipboard(_: &ExportPayload, _: ExportFormat) -> Result<(), PrimeError> {
     Err(PrimeError::Fatal("clipboard feature disabled"))
-}
\ No newline at end of file
+}
@@ -4,14 +4,20 @@
 /// Tests for utility functions
 #[cfg(test)]
 mod util_tests {
-    use crate::primes::PrimeError;
-    use crate::util::copy_to_clipboard;
-    
+    use crate::primes::{PrimeError, PrimeType};
+    use crate::util::{copy_to_clipboard, ExportFormat, ExportPayload};
+
     /// Test clipboard functionality based on feature flag
     #[test]
     fn test_clipboard_function() {
         // This test will have different behavior depending on whether the clipboard feature is enabled
-        let result = copy_to_clipboard("Test data");
+        let payload = ExportPayload {
+            kind: PrimeType::Regular,
+            start: 2,
+            end: 10,
+            values: vec!["2".into(), "3".into(), "5".into(), "7".into()],
+        };
+        let result = copy_to_clipboard(&payload, ExportFormat::PlainText);
         
         // We don't assert on the result directly, since it depends on the feature flag and system state
         // But we can ensure the function doesn't panic
@@ -14,7 +14,7 @@ fn run_calculation(kind: PrimeType, start: u64, end: u64) -> Vec<String> {
 #[test]
 fn test_cache_sequential_requests() {
     // Record the initial cache size
-    let initial_cache_size = cache_stats();
+    let initial_cache_size = cache_stats().entries;
     
     // First run on a range that is likely not in cache
     let calculation_type = PrimeType::Palindromic;
@@ -27,7 +27,7 @@ fn test_cache_sequential_requests() {
     let first_duration = start_time.elapsed();
     
     // Check that the cache size increased
-    let cache_size_after_first = cache_stats();
+    let cache_size_after_first = cache_stats().entries;
     assert!(cache_size_after_first > initial_cache_size, 
             "Cache size should increase after first calculation");
     
@@ -41,7 +41,7 @@ fn test_cache_sequential_requests() {
               "Results should be identical for repeated calculations");
     
     // Cache size should not increase again
-    let cache_size_after_second = cache_stats();
+    let cache_size_after_second = cache_stats().entries;
     assert_eq!(cache_size_after_first, cache_size_after_second, 
               "Cache size should not increase for repeated calculations");
     
@@ -51,7 +51,7 @@ fn test_cache_sequential_requests() {
 // Test for cache behavior with many different types of primes
 #[test]
 fn test_cache_diverse_prime_families() {
-    let initial_cache_size = cache_stats();
+    let initial_cache_size = cache_stats().entries;
     
     // Define a set of prime families to test
     let prime_families = [
@@ -78,7 +78,7 @@ fn test_cache_diverse_prime_families() {
     }
     
     // Cache should have entries for each prime family
-    let cache_size_after = cache_stats();
+    let cache_size_after = cache_stats().entries;
     let expected_increase = prime_families.len();
     assert!(cache_size_after >= initial_cache_size + expected_increase, 
             "Cache should grow by at least {} entries", expected_increase);
@@ -87,7 +87,7 @@ fn test_cache_diverse_prime_families() {
 // Test for cache behavior with overlapping ranges
 #[test]
 fn test_cache_overlapping_ranges() {
-    let initial_cache_size = cache_stats();
+    let initial_cache_size = cache_stats().entries;
     
     // Define a set of overlapping ranges
     let ranges = [
@@ -107,9 +107,9 @@ fn test_cache_overlapping_ranges() {
     }
     
     // Cache should have grown by the number of ranges
-    let cache_size_after = cache_stats();
+    let cache_size_after = cache_stats().entries;
     let expected_increase = ranges.len();
-    assert!(cache_size_after >= initial_cache_size + expected_increase, 
+    assert!(cache_size_after >= initial_cache_size + expected_increase,
             "Cache should grow by at least {} entries", expected_increase);
 }
 
@@ -151,7 +151,7 @@ fn test_consistency_across_ranges() {
 #[test]
 #[ignore] // This test may be slow
 fn test_many_small_ranges() {
-    let initial_cache_size = cache_stats();
+    let initial_cache_size = cache_stats().entries;
     
     // Generate many small non-overlapping ranges
     let prime_type = PrimeType::Twin;
@@ -168,8 +168,8 @@ fn test_many_small_ranges() {
     }
     
     // Cache should have grown by the number of ranges
-    let cache_size_after = cache_stats();
-    assert!(cache_size_after >= initial_cache_size + num_ranges, 
+    let cache_size_after = cache_stats().entries;
+    assert!(cache_size_after >= initial_cache_size + num_ranges,
             "Cache should grow by at least {} entries", num_ranges);
 }
 
@@ -198,7 +198,7 @@ fn test_cache_effectiveness() {
     println!("First run: {:?}, Second run: {:?}", first_duration, second_duration);
     
     // Verify the cache actually contains our query
-    let cache_size = cache_stats();
+    let cache_size = cache_stats().entries;
     assert!(cache_size > 0, "Cache should not be empty after calculations");
 }
 